@@ -31,6 +31,68 @@ impl<I: Iterator<Item = T>, T: Eq> RunLengthEncode<I, T> {
             (_, None) => (0, None),
         }
     }
+
+    /// Advances the iterator by `n` runs. Walks the underlying source directly, comparing items
+    /// to find run boundaries, so a run skipped before the source runs dry never has its
+    /// `(usize, T)` pair built just to be thrown away. Mirrors the `usize`-returning convention of the nightly-only
+    /// `Iterator::advance_by`: returns `0` if all `n` runs were skipped, or the number of runs
+    /// that could not be skipped because the source ran out first.
+    pub fn advance_by(&mut self, mut n: usize) -> usize {
+        while n > 0 {
+            match self.iter.next() {
+                x @ Some(_) if x == self.current_front => self.count += 1,
+                Some(item) => {
+                    if self.current_front.replace(item).is_some() {
+                        n -= 1;
+                    }
+                    self.count = 1;
+                }
+                // The source is empty; hand off to `next` for the runs still held (at most a
+                // front run and a pending `current_back`, which `next` merges if they match).
+                None => {
+                    while n > 0 && self.next().is_some() {
+                        n -= 1;
+                    }
+                    return n;
+                }
+            }
+        }
+        0
+    }
+}
+
+impl<I, T> RunLengthEncode<I, T>
+where
+    I: Iterator<Item = T> + DoubleEndedIterator,
+    T: Eq,
+{
+    /// Advances the iterator from the back by `n` runs, mirroring [`RunLengthEncode::advance_by`].
+    pub fn advance_back_by(&mut self, mut n: usize) -> usize {
+        while n > 0 {
+            match self.iter.next_back() {
+                x @ Some(_) if x == self.current_back => self.count += 1,
+                Some(item) => {
+                    if self.current_back.replace(item).is_some() {
+                        n -= 1;
+                    }
+                    self.count = 1;
+                }
+                None => {
+                    while n > 0 && self.next_back().is_some() {
+                        n -= 1;
+                    }
+                    return n;
+                }
+            }
+        }
+        0
+    }
+
+    /// Returns the final run, found via a single [`next_back`](DoubleEndedIterator::next_back)
+    /// call rather than walking the whole iterator forward.
+    pub fn last(mut self) -> Option<(usize, T)> {
+        self.next_back()
+    }
 }
 
 pub trait IteratorExt: Iterator {
@@ -84,6 +146,176 @@ pub trait IteratorExt: Iterator {
     {
         RunLengthEncode::new(self)
     }
+
+    /// An iterator that reverses a [run-length encoding](https://en.wikipedia.org/wiki/Run-length_encoding),
+    /// flattening items of type `(usize, T)` back into `count` repetitions of `T`. A `count` of `0`
+    /// yields nothing for that pair.
+    ///
+    /// Round-trips with [`IteratorExt::run_length_encode`], i.e. for any `T: Eq + Clone`,
+    /// `xs.run_length_encode().run_length_decode()` reproduces `xs`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use run_length_encode::IteratorExt;
+    /// let runs = vec![(1, '1'), (2, '2'), (3, '3'), (1, '2'), (5, '5')];
+    /// let decoded = runs.into_iter().run_length_decode().collect::<String>();
+    /// assert_eq!(decoded, "122333255555");
+    /// ```
+    ///
+    /// Round-tripping:
+    ///
+    /// ```
+    /// # use run_length_encode::IteratorExt;
+    /// let original: Vec<char> = "122333255555".chars().collect();
+    /// let round_tripped = original
+    ///     .iter()
+    ///     .copied()
+    ///     .run_length_encode()
+    ///     .run_length_decode()
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(original, round_tripped);
+    /// ```
+    fn run_length_decode<T>(self) -> RunLengthDecode<Self, T>
+    where
+        Self: Iterator<Item = (usize, T)> + Sized,
+        T: Clone,
+    {
+        RunLengthDecode::new(self)
+    }
+
+    /// An iterator that yields a [run-length encoding](https://en.wikipedia.org/wiki/Run-length_encoding)
+    /// of the underlying iterator, where runs are delimited by a derived key rather than `T: Eq`
+    /// itself. This method creates a [`RunLengthEncodeByKey`].
+    ///
+    /// Consecutive items for which `f` returns equal keys are grouped into a single run, same as
+    /// [`IteratorExt::run_length_encode`], yielding the first instance of the run when iterating
+    /// forwards and the last instance when iterating backwards.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use run_length_encode::IteratorExt;
+    /// let grouped = [1, 1, 2, 4, 6, 3, 5]
+    ///     .into_iter()
+    ///     .run_length_encode_by_key(|n| n % 2)
+    ///     .collect::<Vec<_>>();
+    /// let expected = vec![(2, 1), (3, 2), (2, 3)];
+    /// assert_eq!(expected, grouped);
+    /// ```
+    fn run_length_encode_by_key<K, F>(self, f: F) -> RunLengthEncodeByKey<Self, K, F>
+    where
+        Self: Iterator + Sized,
+        K: Eq,
+        F: FnMut(&<Self as Iterator>::Item) -> K,
+    {
+        RunLengthEncodeByKey::new(self, f)
+    }
+
+    /// A [PackBits](https://en.wikipedia.org/wiki/PackBits)-style hybrid encoding of the underlying
+    /// iterator. This method creates a [`RunLengthEncodePackbits`].
+    ///
+    /// Unlike [`IteratorExt::run_length_encode`], which pays one `(usize, T)` pair per item on
+    /// high-entropy input, this groups the source into [`Run::Repeat`] tokens for stretches of two
+    /// or more equal items and [`Run::Literal`] tokens for maximal stretches of non-repeating items,
+    /// so runs of length one are batched together instead of each costing a full pair.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use run_length_encode::{IteratorExt, Run};
+    /// let packed = "122333255555".chars().run_length_encode_packbits().collect::<Vec<_>>();
+    /// let expected = vec![
+    ///     Run::Literal(vec!['1']),
+    ///     Run::Repeat(2, '2'),
+    ///     Run::Repeat(3, '3'),
+    ///     Run::Literal(vec!['2']),
+    ///     Run::Repeat(5, '5'),
+    /// ];
+    /// assert_eq!(expected, packed);
+    /// ```
+    ///
+    /// High-entropy input collapses to a single literal:
+    ///
+    /// ```
+    /// # use run_length_encode::{IteratorExt, Run};
+    /// let packed = "501hexdead".chars().run_length_encode_packbits().collect::<Vec<_>>();
+    /// assert_eq!(vec![Run::Literal("501hexdead".chars().collect())], packed);
+    /// ```
+    fn run_length_encode_packbits(self) -> RunLengthEncodePackbits<Self, <Self as Iterator>::Item>
+    where
+        Self: Iterator + Sized,
+        <Self as Iterator>::Item: Eq,
+    {
+        RunLengthEncodePackbits::new(self)
+    }
+
+    /// The inverse of [`IteratorExt::run_length_encode_packbits`]: flattens a stream of [`Run<T>`]
+    /// tokens back into the original sequence of `T`, expanding [`Run::Repeat`] tokens into `count`
+    /// repetitions and [`Run::Literal`] tokens into their contained items in order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use run_length_encode::{IteratorExt, Run};
+    /// let runs = vec![
+    ///     Run::Literal(vec!['1']),
+    ///     Run::Repeat(2, '2'),
+    ///     Run::Repeat(3, '3'),
+    ///     Run::Literal(vec!['2']),
+    ///     Run::Repeat(5, '5'),
+    /// ];
+    /// let decoded = runs.into_iter().run_length_decode_packbits().collect::<String>();
+    /// assert_eq!(decoded, "122333255555");
+    /// ```
+    fn run_length_decode_packbits<T>(self) -> RunLengthDecodePackbits<Self, T>
+    where
+        Self: Iterator<Item = Run<T>> + Sized,
+        T: Clone,
+    {
+        RunLengthDecodePackbits::new(self)
+    }
+
+    /// A [run-length encoding](https://en.wikipedia.org/wiki/Run-length_encoding) of the underlying
+    /// iterator, like [`IteratorExt::run_length_encode`], except no yielded run exceeds `max`. A run
+    /// of `N` equal items longer than `max` is split into `⌈N / max⌉` consecutive pairs whose counts
+    /// sum to `N`, so it can be serialized into a fixed-width count field (e.g. a single byte) without
+    /// a second pass.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max` is `0`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use run_length_encode::IteratorExt;
+    /// let capped = std::iter::repeat_n('x', 600)
+    ///     .run_length_encode_capped(255)
+    ///     .collect::<Vec<_>>();
+    /// let expected = vec![(255, 'x'), (255, 'x'), (90, 'x')];
+    /// assert_eq!(expected, capped);
+    /// ```
+    fn run_length_encode_capped(
+        self,
+        max: usize,
+    ) -> RunLengthEncodeCapped<Self, <Self as Iterator>::Item>
+    where
+        Self: Iterator + Sized,
+        <Self as Iterator>::Item: Eq + Clone,
+    {
+        RunLengthEncodeCapped::new(self, max)
+    }
 }
 
 impl<T> IteratorExt for T where T: Iterator + ?Sized {}
@@ -122,6 +354,36 @@ impl<I: Iterator<Item = T>, T: Eq> Iterator for RunLengthEncode<I, T> {
             }
         }
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if self.advance_by(n) == 0 {
+            self.next()
+        } else {
+            None
+        }
+    }
+
+    fn count(mut self) -> usize {
+        // Tally run boundaries by walking the source directly; only the run still in progress
+        // when the source runs dry (plus a possible pending `current_back`) goes through `next`.
+        let mut total = 0;
+        loop {
+            match self.iter.next() {
+                x @ Some(_) if x == self.current_front => self.count += 1,
+                Some(item) => {
+                    if self.current_front.replace(item).is_some() {
+                        total += 1;
+                    }
+                    self.count = 1;
+                }
+                None => break,
+            }
+        }
+        while self.next().is_some() {
+            total += 1;
+        }
+        total
+    }
 }
 
 impl<I, T> DoubleEndedIterator for RunLengthEncode<I, T>
@@ -162,3 +424,463 @@ where
         }
     }
 }
+
+/// An iterator that reconstitutes the original sequence from a
+/// [run-length encoding](https://en.wikipedia.org/wiki/Run-length_encoding), i.e. the inverse of
+/// [`RunLengthEncode`]. This struct is created by the [`IteratorExt::run_length_decode`] method.
+/// Check its documentation for more information.
+#[derive(Debug, Clone)]
+pub struct RunLengthDecode<I: Iterator<Item = (usize, T)>, T: Clone> {
+    iter: Fuse<I>,
+    current_front: Option<(usize, T)>,
+    current_back: Option<(usize, T)>,
+}
+
+impl<I: Iterator<Item = (usize, T)>, T: Clone> RunLengthDecode<I, T> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter: iter.fuse(),
+            current_front: None,
+            current_back: None,
+        }
+    }
+}
+
+impl<I, T> Iterator for RunLengthDecode<I, T>
+where
+    I: Iterator<Item = (usize, T)>,
+    T: Clone,
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.current_front.as_mut() {
+                Some((remaining, item)) if *remaining > 0 => {
+                    *remaining -= 1;
+                    let out = item.clone();
+                    if *remaining == 0 {
+                        self.current_front = None;
+                    }
+                    return Some(out);
+                }
+                Some(_) => self.current_front = None,
+                None => match self.iter.next() {
+                    Some(pair) => self.current_front = Some(pair),
+                    // The source is dry; if `current_back` is still holding a run (from a prior
+                    // `next_back` call), it's the only thing left and is now ours to drain.
+                    None => match self.current_back.take() {
+                        Some(pair) => self.current_front = Some(pair),
+                        None => return None,
+                    },
+                },
+            }
+        }
+    }
+}
+
+impl<I, T> DoubleEndedIterator for RunLengthDecode<I, T>
+where
+    I: Iterator<Item = (usize, T)> + DoubleEndedIterator,
+    T: Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.current_back.as_mut() {
+                Some((remaining, item)) if *remaining > 0 => {
+                    *remaining -= 1;
+                    let out = item.clone();
+                    if *remaining == 0 {
+                        self.current_back = None;
+                    }
+                    return Some(out);
+                }
+                Some(_) => self.current_back = None,
+                None => match self.iter.next_back() {
+                    Some(pair) => self.current_back = Some(pair),
+                    // The source is dry; if `current_front` is still holding a run (from a prior
+                    // `next` call), it's the only thing left and is now ours to drain.
+                    None => match self.current_front.take() {
+                        Some(pair) => self.current_back = Some(pair),
+                        None => return None,
+                    },
+                },
+            }
+        }
+    }
+}
+
+/// An iterator that yields a [run-length encoding](https://en.wikipedia.org/wiki/Run-length_encoding)
+/// of the underlying iterator, delimiting runs by a key extracted from each item rather than by
+/// `T: Eq`. This struct is created by the [`IteratorExt::run_length_encode_by_key`] method. Check
+/// its documentation for more information.
+#[derive(Debug, Clone)]
+pub struct RunLengthEncodeByKey<I: Iterator<Item = T>, K: Eq, F: FnMut(&T) -> K, T = <I as Iterator>::Item> {
+    iter: Fuse<I>,
+    f: F,
+    count: usize,
+    current_front: Option<T>,
+    current_front_key: Option<K>,
+    current_back: Option<T>,
+    current_back_key: Option<K>,
+}
+
+impl<I: Iterator<Item = T>, K: Eq, F: FnMut(&T) -> K, T> RunLengthEncodeByKey<I, K, F, T> {
+    fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: iter.fuse(),
+            f,
+            count: 0,
+            current_front: None,
+            current_front_key: None,
+            current_back: None,
+            current_back_key: None,
+        }
+    }
+}
+
+impl<I, K, F, T> Iterator for RunLengthEncodeByKey<I, K, F, T>
+where
+    I: Iterator<Item = T>,
+    K: Eq,
+    F: FnMut(&T) -> K,
+{
+    type Item = (usize, T);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next() {
+                Some(item) => {
+                    let key = (self.f)(&item);
+                    match self.current_front.take() {
+                        Some(current) if self.current_front_key.as_ref() == Some(&key) => {
+                            self.current_front = Some(current);
+                            self.count += 1;
+                        }
+                        Some(current) => {
+                            let out = (self.count, current);
+                            self.current_front = Some(item);
+                            self.current_front_key = Some(key);
+                            self.count = 1;
+                            return Some(out);
+                        }
+                        None => {
+                            self.current_front = Some(item);
+                            self.current_front_key = Some(key);
+                            self.count = 1;
+                        }
+                    }
+                }
+                None => match self.current_front.take() {
+                    Some(front_item) => {
+                        let front_key = self.current_front_key.take();
+                        match self.current_back.take() {
+                            Some(back_item) => {
+                                let back_key = self.current_back_key.take();
+                                if front_key == back_key {
+                                    return Some((self.count + 1, front_item));
+                                }
+                                self.current_back = Some(back_item);
+                                self.current_back_key = back_key;
+                                return Some((self.count, front_item));
+                            }
+                            None => return Some((self.count, front_item)),
+                        }
+                    }
+                    None => return self.current_back.take().map(|item| (1, item)),
+                },
+            }
+        }
+    }
+}
+
+impl<I, K, F, T> DoubleEndedIterator for RunLengthEncodeByKey<I, K, F, T>
+where
+    I: Iterator<Item = T> + DoubleEndedIterator,
+    K: Eq,
+    F: FnMut(&T) -> K,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next_back() {
+                Some(item) => {
+                    let key = (self.f)(&item);
+                    match self.current_back.take() {
+                        Some(current) if self.current_back_key.as_ref() == Some(&key) => {
+                            self.current_back = Some(current);
+                            self.count += 1;
+                        }
+                        Some(current) => {
+                            let out = (self.count, current);
+                            self.current_back = Some(item);
+                            self.current_back_key = Some(key);
+                            self.count = 1;
+                            return Some(out);
+                        }
+                        None => {
+                            self.current_back = Some(item);
+                            self.current_back_key = Some(key);
+                            self.count = 1;
+                        }
+                    }
+                }
+                None => match self.current_back.take() {
+                    Some(back_item) => {
+                        let back_key = self.current_back_key.take();
+                        match self.current_front.take() {
+                            Some(front_item) => {
+                                let front_key = self.current_front_key.take();
+                                if front_key == back_key {
+                                    return Some((self.count + 1, back_item));
+                                }
+                                self.current_front = Some(front_item);
+                                self.current_front_key = front_key;
+                                return Some((self.count, back_item));
+                            }
+                            None => return Some((self.count, back_item)),
+                        }
+                    }
+                    None => return self.current_front.take().map(|item| (1, item)),
+                },
+            }
+        }
+    }
+}
+
+/// A single token in a [PackBits](https://en.wikipedia.org/wiki/PackBits)-style hybrid encoding,
+/// produced by [`IteratorExt::run_length_encode_packbits`] and consumed by
+/// [`IteratorExt::run_length_decode_packbits`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Run<T> {
+    /// Two or more consecutive equal items, stored as `(count, item)`.
+    Repeat(usize, T),
+    /// A maximal stretch of non-repeating items, stored in source order.
+    Literal(Vec<T>),
+}
+
+#[derive(Debug, Clone)]
+enum PackbitsPending<T> {
+    Item(T),
+    Repeat(usize, T),
+}
+
+/// An iterator that yields a [PackBits](https://en.wikipedia.org/wiki/PackBits)-style hybrid
+/// encoding of the underlying iterator. This struct is created by the
+/// [`IteratorExt::run_length_encode_packbits`] method. Check its documentation for more
+/// information.
+#[derive(Debug, Clone)]
+pub struct RunLengthEncodePackbits<I: Iterator<Item = T>, T: Eq> {
+    iter: Fuse<I>,
+    pending: Option<PackbitsPending<T>>,
+}
+
+impl<I: Iterator<Item = T>, T: Eq> RunLengthEncodePackbits<I, T> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter: iter.fuse(),
+            pending: None,
+        }
+    }
+
+    fn finish_repeat(&mut self, mut count: usize, item: T) -> Run<T> {
+        loop {
+            match self.iter.next() {
+                Some(x) if x == item => count += 1,
+                Some(x) => {
+                    self.pending = Some(PackbitsPending::Item(x));
+                    break;
+                }
+                None => break,
+            }
+        }
+        Run::Repeat(count, item)
+    }
+}
+
+impl<I, T> Iterator for RunLengthEncodePackbits<I, T>
+where
+    I: Iterator<Item = T>,
+    T: Eq,
+{
+    type Item = Run<T>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut current = match self.pending.take() {
+            Some(PackbitsPending::Repeat(count, item)) => {
+                return Some(self.finish_repeat(count, item))
+            }
+            Some(PackbitsPending::Item(item)) => item,
+            None => self.iter.next()?,
+        };
+        let mut literal = Vec::new();
+        loop {
+            match self.iter.next() {
+                None => {
+                    literal.push(current);
+                    return Some(Run::Literal(literal));
+                }
+                Some(next_item) if next_item == current => {
+                    if literal.is_empty() {
+                        return Some(self.finish_repeat(2, current));
+                    }
+                    self.pending = Some(PackbitsPending::Repeat(2, current));
+                    return Some(Run::Literal(literal));
+                }
+                Some(next_item) => {
+                    literal.push(current);
+                    current = next_item;
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum PackbitsDecodeState<T> {
+    Repeat(usize, T),
+    Literal(std::vec::IntoIter<T>),
+}
+
+/// An iterator that reconstitutes the original sequence from a
+/// [PackBits](https://en.wikipedia.org/wiki/PackBits)-style hybrid encoding, i.e. the inverse of
+/// [`RunLengthEncodePackbits`]. This struct is created by the
+/// [`IteratorExt::run_length_decode_packbits`] method. Check its documentation for more
+/// information.
+#[derive(Debug, Clone)]
+pub struct RunLengthDecodePackbits<I: Iterator<Item = Run<T>>, T: Clone> {
+    iter: Fuse<I>,
+    current: Option<PackbitsDecodeState<T>>,
+}
+
+impl<I: Iterator<Item = Run<T>>, T: Clone> RunLengthDecodePackbits<I, T> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter: iter.fuse(),
+            current: None,
+        }
+    }
+}
+
+impl<I, T> Iterator for RunLengthDecodePackbits<I, T>
+where
+    I: Iterator<Item = Run<T>>,
+    T: Clone,
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.current.as_mut() {
+                Some(PackbitsDecodeState::Repeat(remaining, item)) => {
+                    if *remaining == 0 {
+                        self.current = None;
+                        continue;
+                    }
+                    *remaining -= 1;
+                    let out = item.clone();
+                    if *remaining == 0 {
+                        self.current = None;
+                    }
+                    return Some(out);
+                }
+                Some(PackbitsDecodeState::Literal(items)) => match items.next() {
+                    Some(item) => return Some(item),
+                    None => self.current = None,
+                },
+                None => match self.iter.next() {
+                    Some(Run::Repeat(count, item)) => {
+                        self.current = Some(PackbitsDecodeState::Repeat(count, item))
+                    }
+                    Some(Run::Literal(items)) => {
+                        self.current = Some(PackbitsDecodeState::Literal(items.into_iter()))
+                    }
+                    None => return None,
+                },
+            }
+        }
+    }
+}
+
+/// An iterator that yields a [run-length encoding](https://en.wikipedia.org/wiki/Run-length_encoding)
+/// of the underlying iterator with every run capped to a maximum length. This struct is created by
+/// the [`IteratorExt::run_length_encode_capped`] method. Check its documentation for more
+/// information.
+#[derive(Debug, Clone)]
+pub struct RunLengthEncodeCapped<I: Iterator<Item = T>, T: Eq + Clone> {
+    iter: RunLengthEncode<I, T>,
+    max: usize,
+    pending_front: Option<(usize, T)>,
+    pending_back: Option<(usize, T)>,
+}
+
+impl<I: Iterator<Item = T>, T: Eq + Clone> RunLengthEncodeCapped<I, T> {
+    fn new(iter: I, max: usize) -> Self {
+        assert!(max > 0, "max must be greater than zero");
+        Self {
+            iter: RunLengthEncode::new(iter),
+            max,
+            pending_front: None,
+            pending_back: None,
+        }
+    }
+}
+
+impl<I, T> Iterator for RunLengthEncodeCapped<I, T>
+where
+    I: Iterator<Item = T>,
+    T: Eq + Clone,
+{
+    type Item = (usize, T);
+    fn next(&mut self) -> Option<Self::Item> {
+        // A run that is still mid-split belongs to us first; failing that, pull a fresh run
+        // from the inner iterator. If the inner iterator is out of runs entirely, the only
+        // place a remainder could still be sitting is `pending_back` - the two sides have met
+        // in the middle on a single run and it's now ours to keep splitting.
+        let (remaining, item) = match self.pending_front.take() {
+            Some(pair) => pair,
+            None => match self.iter.next() {
+                Some(pair) => pair,
+                None => self.pending_back.take()?,
+            },
+        };
+        let chunk = remaining.min(self.max);
+        let leftover = remaining - chunk;
+        if leftover > 0 {
+            self.pending_front = Some((leftover, item.clone()));
+        }
+        Some((chunk, item))
+    }
+}
+
+impl<I, T> DoubleEndedIterator for RunLengthEncodeCapped<I, T>
+where
+    I: Iterator<Item = T> + DoubleEndedIterator,
+    T: Eq + Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // Mirror image of `next`: prefer our own pending remainder, then a fresh run from the
+        // inner iterator, then - once the inner iterator has nothing left at all - whatever
+        // `pending_front` is still holding, since that's the tail of the one run we now share.
+        let (remaining, item) = match self.pending_back.take() {
+            Some(pair) => pair,
+            None => match self.iter.next_back() {
+                Some(pair) => pair,
+                None => self.pending_front.take()?,
+            },
+        };
+        // The first chunk seen from the back is whichever chunk the forward split would have
+        // placed last, so the two directions stay mirror images of each other: a remainder
+        // first, then `max`-sized chunks for the rest. Because subtracting whole `max`-sized
+        // chunks never changes `remaining % max`, this same rule stays correct no matter how
+        // much of the run `next` has already peeled off before `next_back` gets to it.
+        let chunk = if remaining <= self.max {
+            remaining
+        } else {
+            match remaining % self.max {
+                0 => self.max,
+                r => r,
+            }
+        };
+        let leftover = remaining - chunk;
+        if leftover > 0 {
+            self.pending_back = Some((leftover, item.clone()));
+        }
+        Some((chunk, item))
+    }
+}
@@ -179,3 +179,331 @@ fn longest_subsequence() {
     let observed = rle.max_by_key(|item| item.0);
     assert_eq!(expected, observed);
 }
+
+#[test]
+fn decode_returns_none_on_empty_source() {
+    let mut decoded = Vec::<(usize, char)>::new().into_iter().run_length_decode();
+    assert!(decoded.next().is_none());
+}
+
+#[test]
+fn decode_reconstructs_runs() {
+    let runs = vec![(1, '1'), (2, '2'), (3, '3'), (1, '2'), (5, '5')];
+    let observed = runs.into_iter().run_length_decode().collect::<String>();
+    let expected = "122333255555";
+    assert_eq!(observed, expected);
+}
+
+#[test]
+fn decode_skips_zero_count_runs() {
+    let runs = vec![(0, 'a'), (2, 'b'), (0, 'c'), (1, 'd')];
+    let observed = runs.into_iter().run_length_decode().collect::<String>();
+    let expected = "bbd";
+    assert_eq!(observed, expected);
+}
+
+#[test]
+fn decode_can_decode_backwards() {
+    let runs = vec![(1, '1'), (2, '2'), (3, '3'), (1, '2'), (5, '5')];
+    let observed = runs
+        .into_iter()
+        .run_length_decode()
+        .rev()
+        .collect::<String>();
+    let expected = "555552333221";
+    assert_eq!(observed, expected);
+}
+
+#[test]
+fn decode_can_decode_forwards_and_backwards_alternating() {
+    let runs = vec![(1, '1'), (2, '2'), (3, '3'), (1, '2'), (5, '5')];
+    let mut decoded = runs.into_iter().run_length_decode();
+    let mut forward = true;
+    let mut total = 0;
+    loop {
+        let next = if forward { decoded.next() } else { decoded.next_back() };
+        forward = !forward;
+        match next {
+            None => break,
+            Some(_) => total += 1,
+        };
+    }
+    assert_eq!(total, "122333255555".len());
+}
+
+#[test]
+fn encode_then_decode_round_trips() {
+    let original = "501hexdead".chars().collect::<Vec<_>>();
+    let observed = original
+        .iter()
+        .copied()
+        .run_length_encode()
+        .run_length_decode()
+        .collect::<Vec<_>>();
+    assert_eq!(original, observed);
+}
+
+#[test]
+fn by_key_groups_on_derived_key() {
+    let observed = [1, 1, 2, 4, 6, 3, 5]
+        .into_iter()
+        .run_length_encode_by_key(|n| n % 2)
+        .collect::<Vec<_>>();
+    let expected = vec![(2, 1), (3, 2), (2, 3)];
+    assert_eq!(expected, observed);
+}
+
+#[test]
+fn by_key_returns_none_on_empty_source() {
+    let mut rle = Vec::<i32>::new().into_iter().run_length_encode_by_key(|n| *n);
+    assert!(rle.next().is_none());
+}
+
+#[test]
+fn by_key_can_encode_backwards() {
+    let observed = [1, 1, 2, 4, 6, 3, 5]
+        .into_iter()
+        .run_length_encode_by_key(|n| n % 2)
+        .rev()
+        .collect::<Vec<_>>();
+    let expected = vec![(2, 5), (3, 6), (2, 1)];
+    assert_eq!(expected, observed);
+}
+
+#[test]
+fn by_key_forward_yields_first_in_subsequence() {
+    let observed = [1, 3, 2, 4, 6]
+        .into_iter()
+        .run_length_encode_by_key(|n| n % 2)
+        .collect::<Vec<_>>();
+    let expected = vec![(2, 1), (3, 2)];
+    assert_eq!(expected, observed);
+}
+
+#[test]
+fn packbits_returns_none_on_empty_source() {
+    let mut packed = Vec::<char>::new().into_iter().run_length_encode_packbits();
+    assert!(packed.next().is_none());
+}
+
+#[test]
+fn packbits_mixes_literal_and_repeat_runs() {
+    let observed = "122333255555"
+        .chars()
+        .run_length_encode_packbits()
+        .collect::<Vec<_>>();
+    let expected = vec![
+        Run::Literal(vec!['1']),
+        Run::Repeat(2, '2'),
+        Run::Repeat(3, '3'),
+        Run::Literal(vec!['2']),
+        Run::Repeat(5, '5'),
+    ];
+    assert_eq!(expected, observed);
+}
+
+#[test]
+fn packbits_collapses_high_entropy_input_to_one_literal() {
+    let observed = "501hexdead"
+        .chars()
+        .run_length_encode_packbits()
+        .collect::<Vec<_>>();
+    let expected = vec![Run::Literal("501hexdead".chars().collect())];
+    assert_eq!(expected, observed);
+}
+
+#[test]
+fn packbits_round_trips() {
+    let original = "122333255555501hexdead".chars().collect::<Vec<_>>();
+    let observed = original
+        .iter()
+        .copied()
+        .run_length_encode_packbits()
+        .run_length_decode_packbits()
+        .collect::<Vec<_>>();
+    assert_eq!(original, observed);
+}
+
+#[test]
+fn packbits_decode_reconstructs_runs() {
+    let runs = vec![
+        Run::Literal(vec!['1']),
+        Run::Repeat(2, '2'),
+        Run::Repeat(3, '3'),
+        Run::Literal(vec!['2']),
+        Run::Repeat(5, '5'),
+    ];
+    let observed = runs
+        .into_iter()
+        .run_length_decode_packbits()
+        .collect::<String>();
+    assert_eq!(observed, "122333255555");
+}
+
+#[test]
+fn capped_splits_long_runs() {
+    let observed = std::iter::repeat_n('x', 600)
+        .run_length_encode_capped(255)
+        .collect::<Vec<_>>();
+    let expected = vec![(255, 'x'), (255, 'x'), (90, 'x')];
+    assert_eq!(expected, observed);
+}
+
+#[test]
+fn capped_leaves_short_runs_untouched() {
+    let observed = "122333255555"
+        .chars()
+        .run_length_encode_capped(255)
+        .collect::<Vec<_>>();
+    let expected = vec![(1, '1'), (2, '2'), (3, '3'), (1, '2'), (5, '5')];
+    assert_eq!(expected, observed);
+}
+
+#[test]
+fn capped_returns_none_on_empty_source() {
+    let mut rle = Vec::<char>::new().into_iter().run_length_encode_capped(2);
+    assert!(rle.next().is_none());
+}
+
+#[test]
+#[should_panic(expected = "max must be greater than zero")]
+fn capped_panics_on_zero_max() {
+    let _ = "abc".chars().run_length_encode_capped(0);
+}
+
+#[test]
+fn capped_can_encode_backwards() {
+    let observed = std::iter::repeat_n('x', 600)
+        .run_length_encode_capped(255)
+        .rev()
+        .collect::<Vec<_>>();
+    let expected = vec![(90, 'x'), (255, 'x'), (255, 'x')];
+    assert_eq!(expected, observed);
+}
+
+#[test]
+fn capped_splits_sum_to_original_count() {
+    let observed = std::iter::repeat_n('x', 600)
+        .run_length_encode_capped(255)
+        .collect::<Vec<_>>();
+    let total: usize = observed.iter().map(|(count, _)| count).sum();
+    assert_eq!(total, 600);
+}
+
+#[test]
+fn capped_can_encode_forwards_and_backwards_alternating_starting_forward() {
+    let mut rle = std::iter::repeat_n('x', 600).run_length_encode_capped(255);
+    let mut forward = true;
+    let mut observed = Vec::new();
+    loop {
+        let next = if forward { rle.next() } else { rle.next_back() };
+        forward = !forward;
+        match next {
+            None => break,
+            Some(x) => observed.push(x),
+        };
+    }
+    let expected = vec![(255, 'x'), (90, 'x'), (255, 'x')];
+    assert_eq!(observed, expected);
+}
+
+#[test]
+fn capped_can_encode_forwards_and_backwards_alternating_starting_backward() {
+    let mut rle = std::iter::repeat_n('x', 600).run_length_encode_capped(255);
+    let mut forward = false;
+    let mut observed = Vec::new();
+    loop {
+        let next = if forward { rle.next() } else { rle.next_back() };
+        forward = !forward;
+        match next {
+            None => break,
+            Some(x) => observed.push(x),
+        };
+    }
+    let expected = vec![(90, 'x'), (255, 'x'), (255, 'x')];
+    assert_eq!(observed, expected);
+}
+
+#[test]
+fn capped_alternating_preserves_total_count_across_runs() {
+    let source = "122333444455555";
+    let mut rle = source.chars().run_length_encode_capped(2);
+    let mut forward = true;
+    let mut total = 0;
+    loop {
+        let next = if forward { rle.next() } else { rle.next_back() };
+        forward = !forward;
+        match next {
+            None => break,
+            Some((count, _)) => total += count,
+        };
+    }
+    assert_eq!(total, source.len());
+}
+
+#[test]
+fn nth_skips_whole_runs() {
+    let mut rle = "122333444455555".chars().run_length_encode();
+    assert_eq!(rle.nth(2), Some((3, '3')));
+    assert_eq!(rle.next(), Some((4, '4')));
+}
+
+#[test]
+fn nth_matches_manual_skip() {
+    let source = "1223336666666666444455555";
+    let mut rle = source.chars().run_length_encode();
+    let nth_result = rle.nth(3);
+    let mut naive = source.chars().run_length_encode();
+    for _ in 0..3 {
+        naive.next();
+    }
+    assert_eq!(nth_result, naive.next());
+    assert_eq!(rle.next(), naive.next());
+}
+
+#[test]
+fn nth_past_end_returns_none() {
+    let mut rle = "122333".chars().run_length_encode();
+    assert_eq!(rle.nth(100), None);
+}
+
+#[test]
+fn advance_by_returns_remainder_past_end() {
+    let mut rle = "122333".chars().run_length_encode();
+    assert_eq!(rle.advance_by(10), 7);
+}
+
+#[test]
+fn advance_by_returns_zero_on_success() {
+    let mut rle = "122333444455555".chars().run_length_encode();
+    assert_eq!(rle.advance_by(2), 0);
+    assert_eq!(rle.next(), Some((3, '3')));
+}
+
+#[test]
+fn count_tallies_total_runs() {
+    let count = "1223336666666666444455555"
+        .chars()
+        .run_length_encode()
+        .count();
+    assert_eq!(count, 6);
+}
+
+#[test]
+fn advance_back_by_skips_from_back() {
+    let mut rle = "122333444455555".chars().run_length_encode();
+    assert_eq!(rle.advance_back_by(2), 0);
+    assert_eq!(rle.next_back(), Some((3, '3')));
+}
+
+#[test]
+fn advance_back_by_returns_remainder_past_end() {
+    let mut rle = "122333".chars().run_length_encode();
+    assert_eq!(rle.advance_back_by(10), 7);
+}
+
+#[test]
+fn last_matches_final_run_via_next_back() {
+    let rle = "122333444455555".chars().run_length_encode();
+    assert_eq!(rle.last(), Some((5, '5')));
+}